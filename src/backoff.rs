@@ -0,0 +1,144 @@
+use core::marker::PhantomData;
+use core::sync::atomic::{AtomicBool, Ordering};
+
+/// A strategy describing how to back off while spinning on a contended lock.
+///
+/// This mirrors the relax-strategy design used by the `spin` crate: a [`relax`](RelaxStrategy::relax)
+/// method that is invoked on every failed spin iteration, allowing callers to tune contention
+/// behavior for a given target, plus an optional [`reset`](RelaxStrategy::reset) hook.
+pub trait RelaxStrategy {
+    /// Performs the relax action for a single spin iteration.
+    fn relax();
+
+    /// Resets any per-contention-episode state once a lock has been acquired.
+    ///
+    /// Strategies that escalate across successive [`relax`](RelaxStrategy::relax) calls (such as
+    /// [`ExponentialBackoff`]) use this to start the next contention episode from scratch; the
+    /// default implementation does nothing.
+    #[inline(always)]
+    fn reset() {}
+}
+
+/// A [`RelaxStrategy`] that emits a CPU spin-loop hint via [`core::hint::spin_loop`].
+///
+/// This is the default strategy and is available on all targets, including `no_std`.
+pub struct Spin;
+
+impl RelaxStrategy for Spin {
+    #[inline(always)]
+    fn relax() {
+        core::hint::spin_loop()
+    }
+}
+
+/// A [`RelaxStrategy`] that yields the current thread's timeslice via
+/// [`std::thread::yield_now`].
+#[cfg(feature = "std")]
+pub struct Yield;
+
+#[cfg(feature = "std")]
+impl RelaxStrategy for Yield {
+    #[inline(always)]
+    fn relax() {
+        std::thread::yield_now()
+    }
+}
+
+/// A [`RelaxStrategy`] that spins for an exponentially growing number of iterations, doubling the
+/// spin count on each invocation up to a cap before yielding the thread.
+///
+/// The spin count is tracked per-thread, so each thread escalates its own backoff independently.
+#[cfg(feature = "std")]
+pub struct ExponentialBackoff;
+
+#[cfg(feature = "std")]
+std::thread_local! {
+    static SPINS: core::cell::Cell<u32> = const { core::cell::Cell::new(0) };
+}
+
+#[cfg(feature = "std")]
+impl RelaxStrategy for ExponentialBackoff {
+    fn relax() {
+        // cap the exponent so the spin count tops out at 2^(SPIN_CAP - 1) before we yield
+        const SPIN_CAP: u32 = 10;
+
+        SPINS.with(|spins| {
+            let exponent = spins.get();
+            if exponent < SPIN_CAP {
+                for _ in 0..(1_u32 << exponent) {
+                    core::hint::spin_loop()
+                }
+                spins.set(exponent + 1);
+            } else {
+                std::thread::yield_now()
+            }
+        })
+    }
+
+    #[inline(always)]
+    fn reset() {
+        // start the next contention episode from scratch so backoff escalation is scoped to a
+        // single acquire rather than accumulating across unrelated locks on this thread
+        SPINS.with(|spins| spins.set(0))
+    }
+}
+
+/// A self-contained spin [`lock_api::RawMutex`] with a selectable [`RelaxStrategy`].
+///
+/// `RawBackoffMutex` is a single `AtomicBool` acquired through a compare-exchange loop. On
+/// contention it performs a test-and-test-and-set: it spin-reads the flag with a relaxed load,
+/// calling `R::relax()` between reads, and only retries the compare-exchange once the flag looks
+/// clear. This keeps the cache line from being hammered by the `CAS` while under contention.
+///
+/// It pulls in no external dependencies, so it is a good default backing for [`crate::LazyMut`]
+/// on bare-metal `no_std` targets where neither `std` nor the `spin` crate is available.
+pub struct RawBackoffMutex<R = Spin> {
+    locked: AtomicBool,
+    relax: PhantomData<fn() -> R>,
+}
+
+unsafe impl<R: RelaxStrategy> lock_api::RawMutex for RawBackoffMutex<R> {
+    #[allow(clippy::declare_interior_mutable_const)]
+    const INIT: Self = RawBackoffMutex {
+        locked: AtomicBool::new(false),
+        relax: PhantomData,
+    };
+
+    type GuardMarker = lock_api::GuardSend;
+
+    fn lock(&self) {
+        while self
+            .locked
+            .compare_exchange_weak(false, true, Ordering::Acquire, Ordering::Relaxed)
+            .is_err()
+        {
+            // test-and-test-and-set: spin on a relaxed read until the flag clears before
+            // attempting the comparatively expensive compare-exchange again
+            while self.is_locked() {
+                R::relax()
+            }
+        }
+        // the lock is ours; reset the relax strategy so the next contention episode escalates
+        // from scratch
+        R::reset()
+    }
+
+    fn try_lock(&self) -> bool {
+        let acquired = self
+            .locked
+            .compare_exchange(false, true, Ordering::Acquire, Ordering::Relaxed)
+            .is_ok();
+        if acquired {
+            R::reset()
+        }
+        acquired
+    }
+
+    unsafe fn unlock(&self) {
+        self.locked.store(false, Ordering::Release)
+    }
+
+    fn is_locked(&self) -> bool {
+        self.locked.load(Ordering::Relaxed)
+    }
+}
@@ -14,35 +14,48 @@ enum InitState<T, F> {
 
 mod poison;
 
+mod backoff;
+pub use backoff::{RawBackoffMutex, RelaxStrategy, Spin};
+
 cfg_if! {
     if #[cfg(feature = "std")] {
         extern crate std;
         mod std_lock;
-        pub use std_lock::RawStdMutex;
+        pub use std_lock::{RawStdMutex, RawStdRwLock};
+        pub use backoff::{ExponentialBackoff, Yield};
     }
 }
 
 macro_rules! declare_lazy_mut {
-    ($default_mutex: path) => {
-        /// Alternative to LazyLock<Mutex<T>> with only a single synchronization step
+    ($default_mutex: path, $default_rwlock: path) => {
+        /// Alternative to `LazyLock<Mutex<T>>` with only a single synchronization step
         pub struct LazyMut<T, F = fn() -> T, M = $default_mutex> {
             state: lock_api::Mutex<M, InitState<T, F>>,
             poison_flag: poison::Flag,
         }
+
+        /// Alternative to `LazyLock<RwLock<T>>` with only a single synchronization step
+        ///
+        /// Unlike [`LazyMut`] shared read-only access does not serialize against other
+        /// readers, so read-heavy workloads avoid the mutex bottleneck.
+        pub struct LazyRwLock<T, F = fn() -> T, R = $default_rwlock> {
+            state: lock_api::RwLock<R, InitState<T, F>>,
+            poison_flag: poison::Flag,
+        }
     };
 }
 
 cfg_if::cfg_if! {
     if #[cfg(feature = "parking_lot")] {
-        declare_lazy_mut!(parking_lot::RawMutex);
+        declare_lazy_mut!(parking_lot::RawMutex, parking_lot::RawRwLock);
     } else if #[cfg(feature = "std")] {
-        declare_lazy_mut!(RawStdMutex);
+        declare_lazy_mut!(RawStdMutex, RawStdRwLock);
     } else if #[cfg(feature = "spin")] {
-        declare_lazy_mut!(spin::Mutex<()>);
+        declare_lazy_mut!(spin::Mutex<()>, spin::RwLock<()>);
     } else {
         #[doc(hidden)]
-        pub enum NoDefaultMutex {}
-        declare_lazy_mut!(NoDefaultMutex);
+        pub enum NoDefaultRwLock {}
+        declare_lazy_mut!(RawBackoffMutex<Spin>, NoDefaultRwLock);
     }
 }
 
@@ -51,6 +64,79 @@ fn lazy_mut_poisoned_init() -> ! {
     panic!("LazyMut instance has been poisoned during initialization")
 }
 
+/// The error returned by [`LazyMut::try_get_mut`] when a guard could not be produced.
+///
+/// This distinguishes the two ways a `LazyMut` can be poisoned: a holder panicking while
+/// holding the lock (recoverable with [`LazyMut::clear_mutex_poison`]) versus the
+/// initialization closure panicking (recoverable with [`LazyMut::reinit`]).
+#[cfg(feature = "std")]
+pub enum TryGetMutError<Guard> {
+    /// Another user of this `LazyMut` panicked while holding it, poisoning the lock. The guard
+    /// is still reachable through [`PoisonError::into_inner`](std::sync::PoisonError::into_inner)
+    /// so callers may recover the data.
+    MutexPoisoned(std::sync::PoisonError<Guard>),
+    /// The initialization closure panicked, leaving the value uninitialized. Install a fresh
+    /// initializer with [`LazyMut::reinit`] to recover.
+    InitPoisoned,
+}
+
+/// The result of [`LazyMut::try_get_mut`]: an exclusive guard, or a [`TryGetMutError`]
+/// distinguishing mutex poisoning from initialization poisoning.
+#[cfg(feature = "std")]
+pub type TryGetMutResult<'a, T, F, M> =
+    Result<LazyMutGuard<'a, T, F, M>, TryGetMutError<LazyMutGuard<'a, T, F, M>>>;
+
+#[cfg(feature = "std")]
+impl<Guard> core::fmt::Debug for TryGetMutError<Guard> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            TryGetMutError::MutexPoisoned(_) => f.debug_struct("MutexPoisoned").finish_non_exhaustive(),
+            TryGetMutError::InitPoisoned => f.write_str("InitPoisoned"),
+        }
+    }
+}
+
+/// # Safety
+/// May only be called when the state is `Uninit`.
+#[cold]
+unsafe fn really_init<T, F: FnOnce() -> T>(state: &mut InitState<T, F>) {
+    let InitState::Uninit(f) = core::mem::replace(state, InitState::Poisoned)
+    // Safety:
+    // caller must uphold that this function is only to be called when the state is `Uninit`.
+    else {
+        unsafe { core::hint::unreachable_unchecked() }
+    };
+
+    let data = f();
+
+    // SAFETY:
+    // If the closure accessed this LazyMut somehow
+    // it will be caught the panic resulting from the state being poisoned,
+    // the mutable borrow for `state` will be invalidated,
+    // The state can only be poisoned at this point,
+    // so using `write` to skip the destructor
+    // of `State` should help the optimizer
+    unsafe { core::ptr::write(state, InitState::Init(data)) }
+}
+
+/// Maps a poison result into a [`std::sync::TryLockResult`], preserving poisoning as
+/// [`TryLockError::Poisoned`](std::sync::TryLockError::Poisoned).
+#[cfg(feature = "std")]
+fn into_try_lock_result<T, U, G>(
+    result: PoisonLockResult<T>,
+    f: G,
+) -> std::sync::TryLockResult<U>
+where
+    G: FnOnce(T) -> U,
+{
+    match result {
+        Ok(x) => Ok(f(x)),
+        Err(err) => Err(std::sync::TryLockError::Poisoned(std::sync::PoisonError::new(
+            f(err.into_inner()),
+        ))),
+    }
+}
+
 /// An RAII implementation of a "scoped lock" of a LazyMutGuard. When this structure is
 /// dropped (falls out of scope), the lock will be unlocked.
 ///
@@ -112,7 +198,7 @@ impl<T, F: FnOnce() -> T, M: lock_api::RawMutex> LazyMut<T, F, M> {
         let state = &mut *lock;
         match state {
             InitState::Init(_) => {}
-            InitState::Uninit(_) => unsafe { Self::really_init(state) },
+            InitState::Uninit(_) => unsafe { really_init(state) },
             InitState::Poisoned => lazy_mut_poisoned_init(),
         }
 
@@ -124,29 +210,6 @@ impl<T, F: FnOnce() -> T, M: lock_api::RawMutex> LazyMut<T, F, M> {
             poison_guard,
         })
     }
-
-    /// # Safety
-    /// May only be called when the state is `Uninit`.
-    #[cold]
-    unsafe fn really_init(state: &mut InitState<T, F>) {
-        let InitState::Uninit(f) = core::mem::replace(state, InitState::Poisoned)
-        // Safety:
-        // caller must uphold that this function is only to be called when the state is `Uninit`.
-        else {
-            unsafe { core::hint::unreachable_unchecked() }
-        };
-
-        let data = f();
-
-        // SAFETY:
-        // If the closure accessed this LazyMut somehow
-        // it will be caught the panic resulting from the state being poisoned,
-        // the mutable borrow for `state` will be invalidated,
-        // The state can only be poisoned at this point,
-        // so using `write` to skip the destructor
-        // of `State` should help the optimizer
-        unsafe { core::ptr::write(state, InitState::Init(data)) }
-    }
 }
 
 impl<T, F: FnOnce() -> T, M: lock_api::RawMutex> LazyMut<T, F, M> {
@@ -180,11 +243,114 @@ impl<T, F: FnOnce() -> T, M: lock_api::RawMutex> LazyMut<T, F, M> {
     /// Forces initialization if not already initialized and returns a mutable guard to the inner data.
     ///
     /// # Errors
-    /// this function errors if another user of this `LazyMut` panicked while holding the `LazyMut` (when its poisoned)
-    /// returns the `LazyMutGuard` wrapped in a Poison Error
+    /// Returns [`TryGetMutError::MutexPoisoned`] if another user of this `LazyMut` panicked while
+    /// holding the `LazyMut`, with the `LazyMutGuard` wrapped in a poison error so the data stays
+    /// reachable. Returns [`TryGetMutError::InitPoisoned`] if the initialization closure panicked;
+    /// unlike [`get_mut`](Self::get_mut) this does not panic, so callers can recover with
+    /// [`reinit`](Self::reinit).
     #[cfg(feature = "std")]
-    pub fn try_get_mut(&self) -> std::sync::LockResult<LazyMutGuard<'_, T, F, M>> {
-        self.force_mut()
+    pub fn try_get_mut(&self) -> TryGetMutResult<'_, T, F, M> {
+        {
+            let mut lock = self.state.lock();
+            let state = &mut *lock;
+            match state {
+                InitState::Init(_) => {}
+                InitState::Uninit(_) => unsafe { really_init(state) },
+                InitState::Poisoned => return Err(TryGetMutError::InitPoisoned),
+            }
+        }
+
+        poison::map_result(self.poison_flag.guard(), |poison_guard| LazyMutGuard {
+            lazy: self,
+            marker: core::marker::PhantomData,
+            poison_guard,
+        })
+        .map_err(TryGetMutError::MutexPoisoned)
+    }
+
+    /// Attempts to acquire the lock without blocking, forcing initialization on success.
+    ///
+    /// This calls the underlying raw mutex's `try_lock`, so a contended `LazyMut` returns
+    /// [`TryLockError::WouldBlock`](std::sync::TryLockError::WouldBlock) instead of blocking,
+    /// making it suitable for latency-sensitive callers. Note that the initialization closure
+    /// runs while the lock is held, so a contended *slow* initialization still blocks the one
+    /// caller that wins the lock.
+    ///
+    /// # Errors
+    /// returns [`TryLockError::WouldBlock`](std::sync::TryLockError::WouldBlock) if the lock is
+    /// currently held, or [`TryLockError::Poisoned`](std::sync::TryLockError::Poisoned) if
+    /// another user panicked while holding the `LazyMut`.
+    ///
+    /// # Panics
+    /// unlike [`try_get_mut`](Self::try_get_mut), this panics (via the same path as
+    /// [`get_mut`](Self::get_mut)) if the initialization closure previously panicked, because the
+    /// [`TryLockResult`](std::sync::TryLockResult) return type cannot express init-poisoning as a
+    /// distinct case. Callers that need to recover from init-poisoning should use
+    /// [`try_get_mut`](Self::try_get_mut) and [`reinit`](Self::reinit).
+    #[cfg(feature = "std")]
+    pub fn try_lock_get_mut(&self) -> std::sync::TryLockResult<LazyMutGuard<'_, T, F, M>> {
+        {
+            let Some(mut lock) = self.state.try_lock() else {
+                return Err(std::sync::TryLockError::WouldBlock);
+            };
+            let state = &mut *lock;
+            match state {
+                InitState::Init(_) => {}
+                InitState::Uninit(_) => unsafe { really_init(state) },
+                InitState::Poisoned => lazy_mut_poisoned_init(),
+            }
+        }
+
+        into_try_lock_result(self.poison_flag.guard(), |poison_guard| LazyMutGuard {
+            lazy: self,
+            marker: core::marker::PhantomData,
+            poison_guard,
+        })
+    }
+
+    /// Returns a mutable guard to the inner data **only if it has already been initialized**,
+    /// without ever running the initialization closure.
+    ///
+    /// Returns `None` while the state is still uninitialized, making this a cheap way to check
+    /// "has this been initialized yet?" and mutate it on the fast path without triggering an
+    /// expensive initialization.
+    ///
+    /// # Panics
+    /// this function panics if another user of this `LazyMut` panicked while holding the `LazyMut`
+    /// or when initialization failed (when its poisoned)
+    pub fn get(&self) -> Option<LazyMutGuard<'_, T, F, M>> {
+        let lock = self.state.lock();
+        match &*lock {
+            InitState::Init(_) => {}
+            InitState::Uninit(_) => return None,
+            InitState::Poisoned => lazy_mut_poisoned_init(),
+        }
+        drop(lock);
+
+        Some(
+            poison::map_result(self.poison_flag.guard(), |poison_guard| LazyMutGuard {
+                lazy: self,
+                marker: core::marker::PhantomData,
+                poison_guard,
+            })
+            .unwrap(),
+        )
+    }
+
+    /// Returns a mutable reference to the inner data **only if it has already been initialized**,
+    /// without ever running the initialization closure.
+    ///
+    /// Since this takes `&mut self` the exclusive borrow proves there is no other access, so no
+    /// locking is required. Returns `None` while the state is still uninitialized.
+    ///
+    /// # Panics
+    /// this function panics if initialization previously failed (when its poisoned)
+    pub fn get_mut_if_init(&mut self) -> Option<&mut T> {
+        match self.state.get_mut() {
+            InitState::Init(data) => Some(data),
+            InitState::Uninit(_) => None,
+            InitState::Poisoned => lazy_mut_poisoned_init(),
+        }
     }
 
     /// Determines whether the `LazyMut` is poisoned.
@@ -203,12 +369,334 @@ impl<T, F: FnOnce() -> T, M: lock_api::RawMutex> LazyMut<T, F, M> {
     pub fn clear_mutex_poison(&self) {
         self.poison_flag.clear()
     }
+
+    /// Recovers from a poisoned initialization by re-seeding the initializer.
+    ///
+    /// If the initialization closure panicked the state is left `InitState::Poisoned`, after
+    /// which every forcing accessor panics (and [`try_get_mut`](Self::try_get_mut) returns
+    /// [`TryGetMutError::InitPoisoned`]). Calling `reinit` replaces that poisoned state with a
+    /// fresh `InitState::Uninit(f)` under the lock, so the next forcing access runs `f` again.
+    /// If the `LazyMut` is already initialized or still uninitialized this has no effect.
+    ///
+    /// The replacement happens under the lock, preserving the invariant that the transition
+    /// `Uninit -> Poisoned -> Init` performed on the next initialization stays atomic, so a
+    /// re-init racing with other accessors can never expose partial data.
+    ///
+    /// Note that the new initializer must have the same type `F` as the original. With the
+    /// default `F = fn() -> T` any named function or non-capturing closure works, but a `LazyMut`
+    /// built from a capturing closure can only be re-seeded with another value of that exact
+    /// closure type, and one built with a `fn` item type only with that same function (so in
+    /// practice a function pointer `F` is required to call `reinit` with a different initializer).
+    pub fn reinit(&self, f: F) {
+        let mut lock = self.state.lock();
+        if matches!(&*lock, InitState::Poisoned) {
+            *lock = InitState::Uninit(f);
+        }
+    }
+}
+
+/// An RAII implementation of a "scoped shared lock" of a `LazyRwLock`. When this structure is
+/// dropped (falls out of scope), the lock will be unlocked.
+///
+/// The data protected by the lock can be accessed through this guard via its `Deref`
+/// implementation.
+#[clippy::has_significant_drop]
+#[must_use = "if unused the LazyRwLock will immediately unlock"]
+pub struct LazyReadGuard<'a, T, F, R: lock_api::RawRwLock> {
+    lazy: &'a LazyRwLock<T, F, R>,
+    poison_guard: poison::Guard,
+    marker: core::marker::PhantomData<(&'a T, R::GuardMarker)>,
+}
+
+/// An RAII implementation of a "scoped exclusive lock" of a `LazyRwLock`. When this structure is
+/// dropped (falls out of scope), the lock will be unlocked.
+///
+/// The data protected by the lock can be accessed through this guard via its `Deref` and
+/// `DerefMut` implementations.
+#[clippy::has_significant_drop]
+#[must_use = "if unused the LazyRwLock will immediately unlock"]
+pub struct LazyWriteGuard<'a, T, F, R: lock_api::RawRwLock> {
+    lazy: &'a LazyRwLock<T, F, R>,
+    poison_guard: poison::Guard,
+    marker: core::marker::PhantomData<(&'a mut T, R::GuardMarker)>,
+}
+
+impl<T, F, R: lock_api::RawRwLock> Deref for LazyReadGuard<'_, T, F, R> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        unsafe {
+            // Safety:
+            // we hold the lock for the data within LazyRwLock
+            let InitState::Init(ref data) = *self.lazy.state.data_ptr()
+            // Safety:
+            // we only create LazyReadGuard's that point to init data
+            else {
+                core::hint::unreachable_unchecked()
+            };
+            data
+        }
+    }
+}
+
+impl<T, F, R: lock_api::RawRwLock> Deref for LazyWriteGuard<'_, T, F, R> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        unsafe {
+            // Safety:
+            // we have exclusive access to the data within LazyRwLock
+            let InitState::Init(ref data) = *self.lazy.state.data_ptr()
+            // Safety:
+            // we only create LazyWriteGuard's that point to init data
+            else {
+                core::hint::unreachable_unchecked()
+            };
+            data
+        }
+    }
+}
+
+impl<T, F, R: lock_api::RawRwLock> DerefMut for LazyWriteGuard<'_, T, F, R> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        unsafe {
+            // Safety:
+            // we have exclusive access to the data within LazyRwLock
+            let InitState::Init(ref mut data) = *self.lazy.state.data_ptr()
+            // Safety:
+            // we only create LazyWriteGuard's that point to init data
+            else {
+                core::hint::unreachable_unchecked()
+            };
+            data
+        }
+    }
+}
+
+impl<T, F, R: lock_api::RawRwLock> Drop for LazyReadGuard<'_, T, F, R> {
+    fn drop(&mut self) {
+        self.lazy.poison_flag.done(self.poison_guard)
+    }
+}
+
+impl<T, F, R: lock_api::RawRwLock> Drop for LazyWriteGuard<'_, T, F, R> {
+    fn drop(&mut self) {
+        self.lazy.poison_flag.done(self.poison_guard)
+    }
+}
+
+impl<T, F, R: lock_api::RawRwLock> core::panic::UnwindSafe for LazyRwLock<T, F, R> {}
+
+impl<T, F: FnOnce() -> T, R: lock_api::RawRwLock> LazyRwLock<T, F, R> {
+    fn force_read(&self) -> PoisonLockResult<LazyReadGuard<'_, T, F, R>> {
+        // Fast path: the data is already initialized, so a shared lock is all we need.
+        if matches!(&*self.state.read(), InitState::Init(_)) {
+            return poison::map_result(self.poison_flag.guard(), |poison_guard| LazyReadGuard {
+                lazy: self,
+                marker: core::marker::PhantomData,
+                poison_guard,
+            });
+        }
+
+        // Slow path: initialization has to happen exactly once under the exclusive lock, so
+        // that no reader can ever observe `InitState::Uninit`.
+        {
+            let mut lock = self.state.write();
+            let state = &mut *lock;
+            match state {
+                InitState::Init(_) => {}
+                InitState::Uninit(_) => unsafe { really_init(state) },
+                InitState::Poisoned => lazy_mut_poisoned_init(),
+            }
+        }
+
+        debug_assert!(matches!(&*self.state.read(), InitState::Init(_)));
+
+        poison::map_result(self.poison_flag.guard(), |poison_guard| LazyReadGuard {
+            lazy: self,
+            marker: core::marker::PhantomData,
+            poison_guard,
+        })
+    }
+
+    fn force_write(&self) -> PoisonLockResult<LazyWriteGuard<'_, T, F, R>> {
+        let mut lock = self.state.write();
+        let state = &mut *lock;
+        match state {
+            InitState::Init(_) => {}
+            InitState::Uninit(_) => unsafe { really_init(state) },
+            InitState::Poisoned => lazy_mut_poisoned_init(),
+        }
+
+        debug_assert!(matches!(state, InitState::Init(_)));
+
+        poison::map_result(self.poison_flag.guard(), |poison_guard| LazyWriteGuard {
+            lazy: self,
+            marker: core::marker::PhantomData,
+            poison_guard,
+        })
+    }
+}
+
+impl<T, F: FnOnce() -> T, R: lock_api::RawRwLock> LazyRwLock<T, F, R> {
+    /// Creates a new `LazyRwLock` with the provided initialization function.
+    #[inline]
+    pub const fn new(f: F) -> Self {
+        LazyRwLock {
+            state: lock_api::RwLock::new(InitState::Uninit(f)),
+            poison_flag: poison::Flag::new(),
+        }
+    }
+
+    /// Consumes the `LazyRwLock` and returns the initialized data, or the initialization function if uninitialized.
+    pub fn into_inner(self) -> Result<T, F> {
+        match self.state.into_inner() {
+            InitState::Init(data) => Ok(data),
+            InitState::Uninit(f) => Err(f),
+            InitState::Poisoned => lazy_mut_poisoned_init(),
+        }
+    }
+
+    /// Forces initialization if not already initialized and returns a shared guard to the inner data.
+    ///
+    /// # Panics
+    /// this function panics if another user of this `LazyRwLock` panicked while holding the `LazyRwLock`
+    /// or when initialization failed (when its poisoned)
+    pub fn read(&self) -> LazyReadGuard<'_, T, F, R> {
+        self.force_read().unwrap()
+    }
+
+    /// Forces initialization if not already initialized and returns an exclusive guard to the inner data.
+    ///
+    /// # Panics
+    /// this function panics if another user of this `LazyRwLock` panicked while holding the `LazyRwLock`
+    /// or when initialization failed (when its poisoned)
+    pub fn write(&self) -> LazyWriteGuard<'_, T, F, R> {
+        self.force_write().unwrap()
+    }
+
+    /// Attempts to acquire a shared lock without blocking, forcing initialization on success.
+    ///
+    /// If initialization has not yet run it is performed once under the exclusive lock, so a
+    /// successful `Ok` never exposes `InitState::Uninit`.
+    ///
+    /// # Errors
+    /// returns [`TryLockError::WouldBlock`](std::sync::TryLockError::WouldBlock) if the lock
+    /// could not be acquired (a writer holds it, or a concurrent initialization is in flight),
+    /// or [`TryLockError::Poisoned`](std::sync::TryLockError::Poisoned) if another user panicked
+    /// while holding the `LazyRwLock`.
+    #[cfg(feature = "std")]
+    pub fn try_read(&self) -> std::sync::TryLockResult<LazyReadGuard<'_, T, F, R>> {
+        // Fast path: already initialized, so a shared lock suffices.
+        let Some(read) = self.state.try_read() else {
+            return Err(std::sync::TryLockError::WouldBlock);
+        };
+        if matches!(&*read, InitState::Init(_)) {
+            drop(read);
+            return into_try_lock_result(self.poison_flag.guard(), |poison_guard| LazyReadGuard {
+                lazy: self,
+                marker: core::marker::PhantomData,
+                poison_guard,
+            });
+        }
+        drop(read);
+
+        // Slow path: initialization has to happen under the exclusive lock.
+        match self.state.try_write() {
+            Some(mut lock) => {
+                let state = &mut *lock;
+                match state {
+                    InitState::Init(_) => {}
+                    InitState::Uninit(_) => unsafe { really_init(state) },
+                    InitState::Poisoned => lazy_mut_poisoned_init(),
+                }
+            }
+            None => return Err(std::sync::TryLockError::WouldBlock),
+        }
+
+        let Some(read) = self.state.try_read() else {
+            return Err(std::sync::TryLockError::WouldBlock);
+        };
+        debug_assert!(matches!(&*read, InitState::Init(_)));
+        drop(read);
+
+        into_try_lock_result(self.poison_flag.guard(), |poison_guard| LazyReadGuard {
+            lazy: self,
+            marker: core::marker::PhantomData,
+            poison_guard,
+        })
+    }
+
+    /// Attempts to acquire an exclusive lock without blocking, forcing initialization on success.
+    ///
+    /// # Errors
+    /// returns [`TryLockError::WouldBlock`](std::sync::TryLockError::WouldBlock) if the lock
+    /// could not be acquired, or [`TryLockError::Poisoned`](std::sync::TryLockError::Poisoned)
+    /// if another user panicked while holding the `LazyRwLock`.
+    #[cfg(feature = "std")]
+    pub fn try_write(&self) -> std::sync::TryLockResult<LazyWriteGuard<'_, T, F, R>> {
+        let Some(mut lock) = self.state.try_write() else {
+            return Err(std::sync::TryLockError::WouldBlock);
+        };
+        let state = &mut *lock;
+        match state {
+            InitState::Init(_) => {}
+            InitState::Uninit(_) => unsafe { really_init(state) },
+            InitState::Poisoned => lazy_mut_poisoned_init(),
+        }
+
+        debug_assert!(matches!(state, InitState::Init(_)));
+
+        into_try_lock_result(self.poison_flag.guard(), |poison_guard| LazyWriteGuard {
+            lazy: self,
+            marker: core::marker::PhantomData,
+            poison_guard,
+        })
+    }
+
+    /// Determines whether the `LazyRwLock` is poisoned.
+    ///
+    /// If another thread is active, the `LazyRwLock` can still become poisoned at any
+    /// time. You should not trust a `false` value for program correctness
+    /// without additional synchronization.
+    pub fn is_poisoned(&self) -> bool {
+        matches!(&*self.state.read(), InitState::Poisoned) || self.poison_flag.get()
+    }
+
+    /// Clear the poisoned lock state from a `LazyRwLock`.
+    ///
+    /// If a holder of the `LazyRwLock` panicked, it will remain poisoned until this function is
+    /// called. This allows recovering from a poisoned state and marking that it has recovered.
+    /// Poisoning caused by a panicking initialization closure is not cleared by this; use
+    /// [`reinit`](Self::reinit) for that.
+    pub fn clear_mutex_poison(&self) {
+        self.poison_flag.clear()
+    }
+
+    /// Recovers from a poisoned initialization by re-seeding the initializer.
+    ///
+    /// If the initialization closure panicked the state is left `InitState::Poisoned`, after
+    /// which every forcing accessor panics. Calling `reinit` replaces that poisoned state with a
+    /// fresh `InitState::Uninit(f)` under the exclusive lock, so the next forcing access runs `f`
+    /// again. If the `LazyRwLock` is already initialized or still uninitialized this has no effect.
+    ///
+    /// The replacement happens under the exclusive lock, preserving the invariant that the
+    /// transition `Uninit -> Poisoned -> Init` performed on the next initialization stays atomic,
+    /// so a re-init racing with readers can never expose partial data.
+    pub fn reinit(&self, f: F) {
+        let mut lock = self.state.write();
+        if matches!(&*lock, InitState::Poisoned) {
+            *lock = InitState::Uninit(f);
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     extern crate std;
     use crate::LazyMut;
+    #[cfg(any(feature = "std", feature = "parking_lot", feature = "spin"))]
+    use crate::LazyRwLock;
 
     macro_rules! gen_test {
         ($name:ident $mutex_ty:ty) => {
@@ -234,9 +722,164 @@ mod tests {
     #[cfg(feature = "std")]
     gen_test!(std_test crate::RawStdMutex);
 
+    // non-forcing accessors must not run the init closure, and must surface init-poison rather
+    // than the opaque inner-lock error, on the default std backend
+    #[cfg(feature = "std")]
+    #[test]
+    fn std_non_forcing_accessors() {
+        use crate::RawStdMutex;
+        use std::panic::{catch_unwind, AssertUnwindSafe};
+
+        let mut x = LazyMut::<u64, fn() -> u64, RawStdMutex>::new(|| 5);
+        // uninitialized: neither accessor forces the closure
+        assert!(x.get().is_none());
+        assert!(x.get_mut_if_init().is_none());
+        // force init, then both observe the value
+        assert_eq!(*x.get_mut(), 5);
+        assert!(x.get().is_some());
+        assert_eq!(*x.get_mut_if_init().unwrap(), 5);
+
+        // after an init panic, `get` reports the poison via a panic, not an internal error
+        fn boom() -> u64 {
+            panic!("boom")
+        }
+        let y = LazyMut::<u64, fn() -> u64, RawStdMutex>::new(boom);
+        assert!(catch_unwind(AssertUnwindSafe(|| y.get_mut())).is_err());
+        assert!(catch_unwind(AssertUnwindSafe(|| y.get())).is_err());
+    }
+
     #[cfg(feature = "parking_lot")]
     gen_test!(parking_lot_test parking_lot::RawMutex);
 
     #[cfg(feature = "spin")]
     gen_test!(spin_test spin::Mutex<()>);
+
+    gen_test!(backoff_spin_test crate::RawBackoffMutex<crate::Spin>);
+
+    #[cfg(feature = "std")]
+    gen_test!(backoff_yield_test crate::RawBackoffMutex<crate::Yield>);
+
+    #[cfg(feature = "std")]
+    gen_test!(backoff_exponential_test crate::RawBackoffMutex<crate::ExponentialBackoff>);
+
+    // recovery from a poisoned init closure on the default std backend: `try_get_mut` must report
+    // `InitPoisoned` (not panic with an internal error) and `reinit` must re-seed the initializer
+    #[cfg(feature = "std")]
+    #[test]
+    fn std_reinit_recovers_from_init_poison() {
+        use crate::{RawStdMutex, TryGetMutError};
+        use std::panic::{catch_unwind, AssertUnwindSafe};
+
+        fn boom() -> u64 {
+            panic!("boom")
+        }
+        fn forty_two() -> u64 {
+            42
+        }
+
+        let x = LazyMut::<u64, fn() -> u64, RawStdMutex>::new(boom);
+        // forcing init panics, leaving the state init-poisoned
+        assert!(catch_unwind(AssertUnwindSafe(|| x.get_mut())).is_err());
+        // init-poison is surfaced as its own error, distinct from mutex poison
+        assert!(matches!(x.try_get_mut(), Err(TryGetMutError::InitPoisoned)));
+        // re-seed and recover
+        x.reinit(forty_two);
+        assert_eq!(*x.get_mut(), 42);
+    }
+
+    // a panic while holding the guard poisons via `poison::Flag`, reported as `MutexPoisoned`
+    // (with the data still reachable) and clearable — distinct from init poison above
+    #[cfg(feature = "std")]
+    #[test]
+    fn std_mutex_poison_distinct_from_init_poison() {
+        use crate::{RawStdMutex, TryGetMutError};
+        use std::panic::{catch_unwind, AssertUnwindSafe};
+
+        let x = LazyMut::<u64, fn() -> u64, RawStdMutex>::new(|| 0);
+        let _ = catch_unwind(AssertUnwindSafe(|| {
+            let _g = x.get_mut();
+            panic!("holder panicked");
+        }));
+        match x.try_get_mut() {
+            Err(TryGetMutError::MutexPoisoned(err)) => assert_eq!(*err.into_inner(), 0),
+            _ => panic!("expected MutexPoisoned"),
+        }
+        x.clear_mutex_poison();
+        assert_eq!(*x.get_mut(), 0);
+    }
+
+    #[cfg(any(feature = "std", feature = "parking_lot", feature = "spin"))]
+    macro_rules! gen_rwlock_test {
+        ($name:ident $rwlock_ty:ty) => {
+            #[test]
+            fn $name() {
+                let x = LazyRwLock::<u64, _, $rwlock_ty>::new(|| 0_u64);
+                std::thread::scope(|s| {
+                    for _ in 0..32 {
+                        s.spawn(|| {
+                            for i in 1..=10 {
+                                {
+                                    let mut lock = x.write();
+                                    *lock += 100;
+                                    assert!(*lock >= 100 * i);
+                                }
+                                // shared readers must never observe the uninitialized state
+                                assert!(*x.read() >= 100);
+                            }
+                        });
+                    }
+                });
+                assert_eq!(*x.read(), 32 * 10 * 100);
+            }
+        };
+    }
+
+    // a contended `LazyMut` must report `WouldBlock` from the non-blocking accessor, never block
+    #[cfg(feature = "std")]
+    #[test]
+    fn std_try_lock_would_block() {
+        use crate::RawStdMutex;
+        use std::sync::TryLockError;
+
+        let x = LazyMut::<u64, fn() -> u64, RawStdMutex>::new(|| 7);
+        let held = x.get_mut();
+        std::thread::scope(|s| {
+            let blocked = s
+                .spawn(|| matches!(x.try_lock_get_mut(), Err(TryLockError::WouldBlock)))
+                .join()
+                .unwrap();
+            assert!(blocked);
+        });
+        assert_eq!(*held, 7);
+    }
+
+    #[cfg(feature = "std")]
+    gen_rwlock_test!(std_rwlock_test crate::RawStdRwLock);
+
+    #[cfg(feature = "parking_lot")]
+    gen_rwlock_test!(parking_lot_rwlock_test parking_lot::RawRwLock);
+
+    #[cfg(feature = "spin")]
+    gen_rwlock_test!(spin_rwlock_test spin::RwLock<()>);
+
+    // an init panic in `force_write` poisons the inner std rwlock; `reinit` and the following
+    // `read` must recover from that poisoned inner lock rather than panic
+    #[cfg(feature = "std")]
+    #[test]
+    fn std_rwlock_reinit_recovers_from_init_poison() {
+        use crate::RawStdRwLock;
+        use std::panic::{catch_unwind, AssertUnwindSafe};
+
+        fn boom() -> u64 {
+            panic!("boom")
+        }
+        fn forty_two() -> u64 {
+            42
+        }
+
+        let x = LazyRwLock::<u64, fn() -> u64, RawStdRwLock>::new(boom);
+        assert!(catch_unwind(AssertUnwindSafe(|| x.write())).is_err());
+        x.reinit(forty_two);
+        assert_eq!(*x.read(), 42);
+    }
 }
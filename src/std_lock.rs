@@ -4,6 +4,7 @@ use std::marker::PhantomData;
 use std::mem::MaybeUninit;
 use std::pin::Pin;
 use std::sync::TryLockError;
+use std::vec::Vec;
 
 #[cold]
 fn init_inner_mutex() -> Pin<Box<Inner>> {
@@ -76,10 +77,17 @@ unsafe impl lock_api::RawMutex for RawStdMutex {
     type GuardMarker = PhantomData<std::sync::MutexGuard<'static, ()>>;
 
     fn lock(&self) {
-        match self.0.get_or_init(init_inner_mutex).lock.lock() {
-            Ok(guard) => unsafe { self.save_guard(guard) },
-            Err(_) => unreachable!(),
-        }
+        // A `LazyMut` init closure that panics unwinds through `really_init` while holding this
+        // guard, poisoning the inner mutex. That poison is expected and recoverable (`LazyMut`
+        // records init poisoning in its own state and holder panics via `poison::Flag`), so
+        // recover the guard rather than treating it as unreachable.
+        let guard = self
+            .0
+            .get_or_init(init_inner_mutex)
+            .lock
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        unsafe { self.save_guard(guard) }
     }
 
     fn try_lock(&self) -> bool {
@@ -89,7 +97,11 @@ unsafe impl lock_api::RawMutex for RawStdMutex {
                 true
             }
             Err(TryLockError::WouldBlock) => false,
-            Err(TryLockError::Poisoned(_)) => unreachable!(),
+            // recover from a poisoned inner mutex; see `lock`
+            Err(TryLockError::Poisoned(err)) => {
+                unsafe { self.save_guard(err.into_inner()) }
+                true
+            }
         }
     }
 
@@ -109,7 +121,201 @@ unsafe impl lock_api::RawMutex for RawStdMutex {
         match this.lock.try_lock() {
             Ok(_) => false,
             Err(TryLockError::WouldBlock) => true,
-            Err(TryLockError::Poisoned(_)) => unreachable!(),
+            // a poisoned-but-acquirable mutex is not currently locked
+            Err(TryLockError::Poisoned(_)) => false,
+        }
+    }
+}
+
+#[cold]
+fn init_inner_rwlock() -> Pin<Box<RwInner>> {
+    Box::pin(RwInner {
+        lock: std::sync::RwLock::new(()),
+        read_guards: std::sync::Mutex::new(Vec::new()),
+        write_guard: UnsafeCell::new(MaybeUninit::uninit()),
+    })
+}
+
+struct RwInner {
+    lock: std::sync::RwLock<()>,
+    // shared guards are interchangeable (they all guard `()`), so we stash them in a
+    // small collection and drop an arbitrary one on every `unlock_shared`.
+    read_guards: std::sync::Mutex<Vec<std::sync::RwLockReadGuard<'static, ()>>>,
+    write_guard: UnsafeCell<MaybeUninit<std::sync::RwLockWriteGuard<'static, ()>>>,
+}
+
+/// A low-level raw reader-writer lock implementation for use with the `lock_api` crate.
+///
+/// `RawStdRwLock` is the read/write counterpart to [`RawStdMutex`], wrapping a
+/// [`std::sync::RwLock`] and providing the `lock_api::RawRwLock` interface.
+///
+/// # Features
+/// - Compatible with the `lock_api` crate for building advanced synchronization primitives.
+/// - Ensures safety and synchronization via the internal use of `std::sync::RwLock`.
+///
+/// # Notes
+/// - This struct is intended to be used as a foundational component for custom
+///   synchronization abstractions and is not generally used directly in application code.
+/// - The implementation follows `lock_api`'s `RawRwLock` requirements, such as methods
+///   for shared/exclusive locking, unlocking, and checking lock status.
+///
+/// # Safety
+/// - Correct usage of this struct requires careful adherence to locking and unlocking
+///   sequences to avoid undefined behavior.
+/// - Safe usage assumes compliance with the `lock_api` contract.
+pub struct RawStdRwLock(std::sync::OnceLock<Pin<Box<RwInner>>>);
+
+// access to the UnsafeCell is synchronized by the inner RwLock, and the guard stash by its own mutex
+unsafe impl Send for RawStdRwLock {}
+unsafe impl Sync for RawStdRwLock {}
+
+impl RawStdRwLock {
+    // Safety:
+    // the guard is produced by the rwlock `read` within self
+    unsafe fn save_read_guard(&self, guard: std::sync::RwLockReadGuard<'_, ()>) {
+        unsafe {
+            #[allow(clippy::needless_lifetimes)]
+            unsafe fn extend_life<'a, 'b>(
+                x: std::sync::RwLockReadGuard<'a, ()>,
+            ) -> std::sync::RwLockReadGuard<'b, ()> {
+                unsafe { core::mem::transmute(x) }
+            }
+
+            // Safety:
+            // user guarantees that the guard was produced by the rwlock `read` within self
+            // meaning that the OnceLock has to have been initialized
+            let this = &**self.0.get().unwrap_unchecked();
+
+            // Safety:
+            // the rwlock is pinned on the heap behind the OnceLock and therefore outlives
+            // any `'static` transmute we perform here, the stash is itself guarded by a mutex
+            this.read_guards
+                .lock()
+                .unwrap_or_else(std::sync::PoisonError::into_inner)
+                .push(extend_life(guard))
+        }
+    }
+
+    // Safety:
+    // the guard is produced by the rwlock `write` within self
+    unsafe fn save_write_guard(&self, guard: std::sync::RwLockWriteGuard<'_, ()>) {
+        unsafe {
+            #[allow(clippy::needless_lifetimes)]
+            unsafe fn extend_life<'a, 'b>(
+                x: std::sync::RwLockWriteGuard<'a, ()>,
+            ) -> std::sync::RwLockWriteGuard<'b, ()> {
+                unsafe { core::mem::transmute(x) }
+            }
+
+            // Safety:
+            // user guarantees that the guard was produced by the rwlock `write` within self
+            // meaning that the OnceLock has to have been initialized
+            let this = &**self.0.get().unwrap_unchecked();
+
+            // Safety:
+            // we have exclusive access to ourselves and this self reference is valid as it's
+            // pinned on the heap, therefore it is safe to transmute this lifetime such that it
+            // lives longer
+            *this.write_guard.get() = MaybeUninit::new(extend_life(guard))
+        }
+    }
+}
+
+unsafe impl lock_api::RawRwLock for RawStdRwLock {
+    #[allow(clippy::declare_interior_mutable_const)]
+    const INIT: Self = Self(std::sync::OnceLock::new());
+
+    type GuardMarker = PhantomData<std::sync::RwLockWriteGuard<'static, ()>>;
+
+    fn lock_shared(&self) {
+        // An init closure that panics in `force_write` poisons the inner rwlock. That poison is
+        // expected and recoverable (`LazyRwLock` records init poisoning in its own state and
+        // holder panics via `poison::Flag`), so recover the guard rather than treating it as
+        // unreachable.
+        let guard = self
+            .0
+            .get_or_init(init_inner_rwlock)
+            .lock
+            .read()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        unsafe { self.save_read_guard(guard) }
+    }
+
+    fn try_lock_shared(&self) -> bool {
+        match self.0.get_or_init(init_inner_rwlock).lock.try_read() {
+            Ok(guard) => {
+                unsafe { self.save_read_guard(guard) }
+                true
+            }
+            Err(TryLockError::WouldBlock) => false,
+            // recover from a poisoned inner rwlock; see `lock_shared`
+            Err(TryLockError::Poisoned(err)) => {
+                unsafe { self.save_read_guard(err.into_inner()) }
+                true
+            }
+        }
+    }
+
+    unsafe fn unlock_shared(&self) {
+        // Safety:
+        // caller upholds that we did indeed `lock_shared` before this, therefore the
+        // OnceLock is initialized and the stash holds at least one shared guard; since all
+        // shared guards are interchangeable we may drop an arbitrary one
+        unsafe {
+            let this = &**self.0.get().unwrap_unchecked();
+            let mut guards = this
+                .read_guards
+                .lock()
+                .unwrap_or_else(std::sync::PoisonError::into_inner);
+            drop(guards.pop().unwrap_unchecked());
+        }
+    }
+
+    fn lock_exclusive(&self) {
+        // recover from a poisoned inner rwlock; see `lock_shared`
+        let guard = self
+            .0
+            .get_or_init(init_inner_rwlock)
+            .lock
+            .write()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        unsafe { self.save_write_guard(guard) }
+    }
+
+    fn try_lock_exclusive(&self) -> bool {
+        match self.0.get_or_init(init_inner_rwlock).lock.try_write() {
+            Ok(guard) => {
+                unsafe { self.save_write_guard(guard) }
+                true
+            }
+            Err(TryLockError::WouldBlock) => false,
+            // recover from a poisoned inner rwlock; see `lock_shared`
+            Err(TryLockError::Poisoned(err)) => {
+                unsafe { self.save_write_guard(err.into_inner()) }
+                true
+            }
+        }
+    }
+
+    unsafe fn unlock_exclusive(&self) {
+        // Safety:
+        // caller upholds that we did indeed `lock_exclusive` before this, therefore there is
+        // in fact a write guard in this slot and RwInner has been initialized
+        unsafe {
+            MaybeUninit::assume_init_drop(&mut *self.0.get().unwrap_unchecked().write_guard.get())
+        }
+    }
+
+    fn is_locked(&self) -> bool {
+        let Some(this) = self.0.get() else {
+            return false;
+        };
+
+        match this.lock.try_write() {
+            Ok(_) => false,
+            Err(TryLockError::WouldBlock) => true,
+            // a poisoned-but-acquirable rwlock is not currently locked
+            Err(TryLockError::Poisoned(_)) => false,
         }
     }
 }